@@ -1,3 +1,6 @@
+mod parse;
+
+use parse::Command;
 use rand::Rng;
 use std::cmp::Ordering;
 use std::io;
@@ -6,10 +9,12 @@ fn main() {
     println!("Guess the number!");
 
     // Initialise random number
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+    let mut low = 1;
+    let mut high = 100;
+    let mut secret_number = rand::thread_rng().gen_range(low..=high);
 
     loop {
-        println!("Please input your guess.");
+        println!("Please input your guess (or `guess N`, `range LOW HIGH`, `quit`).");
 
         let mut guess = String::new();
 
@@ -18,10 +23,45 @@ fn main() {
             .read_line(&mut guess)
             .expect("Failed to read line.");
 
-        // Parse input -> number. Continue and ask again if Err
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => continue,
+        let trimmed = guess.trim();
+
+        // Try the richer command grammar first (quit / guess N / range LOW HIGH)
+        let guess: u32 = match parse::command(trimmed) {
+            Some(Command::Quit) => break,
+            Some(Command::Range(new_low, new_high)) => {
+                if new_low > new_high {
+                    println!("Invalid range: {new_low}..={new_high} (low must not be greater than high).");
+                    continue;
+                }
+
+                low = new_low;
+                high = new_high;
+                secret_number = rand::thread_rng().gen_range(low..=high);
+                println!("New range set: {low}..={high}");
+                continue;
+            },
+            Some(Command::Guess(num)) => num,
+            None => {
+                // Fall back to a bare number, detecting a leading 0x/0b/0o prefix
+                // to pick the radix to parse with.
+                let (radix, rest) = if let Some(stripped) = trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+                    (16, stripped)
+                } else if let Some(stripped) = trimmed.strip_prefix("0b").or_else(|| trimmed.strip_prefix("0B")) {
+                    (2, stripped)
+                } else if let Some(stripped) = trimmed.strip_prefix("0o").or_else(|| trimmed.strip_prefix("0O")) {
+                    (8, stripped)
+                } else {
+                    (10, trimmed)
+                };
+
+                match u32::from_str_radix(rest, radix) {
+                    Ok(num) => num,
+                    Err(_) => {
+                        println!("Could not parse '{trimmed}' as a base {radix} number.");
+                        continue;
+                    },
+                }
+            },
         };
 
         println!("You guessed: {guess}");
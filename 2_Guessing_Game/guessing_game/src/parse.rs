@@ -0,0 +1,204 @@
+// Small parser-combinator helpers for turning a line of input into a `Command`,
+// modelled on the `yap`-style combinator approach.
+
+use std::str::Chars;
+
+/// The set of commands the guessing game can accept.
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Guess(u32),
+    Range(u32, u32),
+    Quit,
+}
+
+/// Wraps a `Chars` iterator with a cursor so combinators can save/restore position on failure.
+pub struct Tokens<'a> {
+    chars: Chars<'a>,
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(input: &'a str) -> Tokens<'a> {
+        Tokens {
+            chars: input.chars(),
+            rest: input,
+        }
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.chars.clone().next()
+    }
+
+    pub fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        self.rest = self.chars.as_str();
+        c
+    }
+
+    /// Consumes characters matching `pred`, returning the consumed slice.
+    pub fn take_while<F: Fn(char) -> bool>(&mut self, pred: F) -> &'a str {
+        let start = self.rest;
+        let mut len = 0;
+
+        while let Some(c) = self.peek() {
+            if !pred(c) {
+                break;
+            }
+            len += c.len_utf8();
+            self.next();
+        }
+
+        &start[..len]
+    }
+
+    pub fn eat_whitespace(&mut self) {
+        self.take_while(|c| c.is_whitespace());
+    }
+
+    fn position(&self) -> &'a str {
+        self.rest
+    }
+
+    fn restore(&mut self, position: &'a str) {
+        self.chars = position.chars();
+        self.rest = position;
+    }
+}
+
+/// Parses an unsigned integer in the given radix, restoring the cursor on failure.
+pub fn parse_uint(tokens: &mut Tokens, radix: u32) -> Option<u32> {
+    let saved = tokens.position();
+
+    let digits = tokens.take_while(|c| c.is_digit(radix));
+    match u32::from_str_radix(digits, radix) {
+        Ok(n) => Some(n),
+        Err(_) => {
+            tokens.restore(saved);
+            None
+        },
+    }
+}
+
+/// Matches the literal keyword `word`, restoring the cursor on failure.
+pub fn keyword(tokens: &mut Tokens, word: &str) -> Option<()> {
+    let saved = tokens.position();
+
+    let matched = tokens.take_while(|c| !c.is_whitespace());
+    if matched == word {
+        Some(())
+    } else {
+        tokens.restore(saved);
+        None
+    }
+}
+
+/// Tries each parser in turn, restoring the cursor between attempts so it can backtrack cleanly.
+///
+/// Snapshots the cursor before each candidate and restores it on failure, so a candidate that
+/// matches part-way before failing (e.g. a keyword followed by a malformed argument) can't leave
+/// the cursor partway through consumed input for the next candidate to pick up from.
+pub fn alt<T>(tokens: &mut Tokens, parsers: &[&dyn Fn(&mut Tokens) -> Option<T>]) -> Option<T> {
+    for parser in parsers {
+        let saved = tokens.position();
+
+        if let Some(result) = parser(tokens) {
+            return Some(result);
+        }
+
+        tokens.restore(saved);
+    }
+
+    None
+}
+
+fn guess_command(tokens: &mut Tokens) -> Option<Command> {
+    keyword(tokens, "guess")?;
+    tokens.eat_whitespace();
+    parse_uint(tokens, 10).map(Command::Guess)
+}
+
+fn range_command(tokens: &mut Tokens) -> Option<Command> {
+    keyword(tokens, "range")?;
+    tokens.eat_whitespace();
+    let low = parse_uint(tokens, 10)?;
+    tokens.eat_whitespace();
+    let high = parse_uint(tokens, 10)?;
+    Some(Command::Range(low, high))
+}
+
+fn quit_command(tokens: &mut Tokens) -> Option<Command> {
+    keyword(tokens, "quit")?;
+    Some(Command::Quit)
+}
+
+/// Parses a line of input into a `Command`.
+///
+/// Fails if anything but trailing whitespace is left over after a successful match, so
+/// e.g. `"guess 42 extra"` is rejected rather than silently dropping `"extra"`.
+pub fn command(input: &str) -> Option<Command> {
+    let mut tokens = Tokens::new(input);
+    tokens.eat_whitespace();
+
+    let command = alt(&mut tokens, &[&quit_command, &guess_command, &range_command])?;
+
+    tokens.eat_whitespace();
+    if tokens.peek().is_some() {
+        return None;
+    }
+
+    Some(command)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_parses_quit() {
+        assert_eq!(command("quit"), Some(Command::Quit));
+    }
+
+    #[test]
+    fn command_parses_guess() {
+        assert_eq!(command("guess 42"), Some(Command::Guess(42)));
+    }
+
+    #[test]
+    fn command_parses_range() {
+        assert_eq!(command("range 1 50"), Some(Command::Range(1, 50)));
+    }
+
+    #[test]
+    fn command_rejects_trailing_input() {
+        assert_eq!(command("guess 42 extra"), None);
+        assert_eq!(command("quit now"), None);
+        assert_eq!(command("range 1 50 60"), None);
+    }
+
+    #[test]
+    fn command_rejects_unknown_keyword() {
+        assert_eq!(command("foo"), None);
+    }
+
+    #[test]
+    fn alt_backtracks_past_a_partially_consumed_failing_candidate() {
+        // `guess_command` consumes the "guess" keyword and whitespace before failing to find
+        // digits; `alt` must restore the cursor so `range_command` (and ultimately the whole
+        // parse) sees the untouched original input rather than a half-eaten remainder.
+        assert_eq!(command("guess range 1 50"), None);
+    }
+
+    #[test]
+    fn keyword_restores_cursor_on_mismatch() {
+        let mut tokens = Tokens::new("range 1 50");
+        assert_eq!(keyword(&mut tokens, "guess"), None);
+        assert_eq!(tokens.peek(), Some('r'));
+    }
+
+    #[test]
+    fn parse_uint_restores_cursor_on_mismatch() {
+        let mut tokens = Tokens::new("abc");
+        assert_eq!(parse_uint(&mut tokens, 10), None);
+        assert_eq!(tokens.peek(), Some('a'));
+    }
+}
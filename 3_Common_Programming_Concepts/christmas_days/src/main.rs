@@ -1,51 +1,96 @@
+const PARTRIDGE: &str = "a partridge in a pair tree";
+
+const LINES: [&str; 11] = [
+    "two turtle doves",
+    "three French hens",
+    "four calling birds",
+    "fiiiiiivvvve gooolllddd riinnnggs", // necessary!
+    "six geese a-laying",
+    "seven swans a-swimming",
+    "eight maids a-milking",
+    "nine ladies dancing",
+    "ten lords a-leaping",
+    "eleven pipers piping",
+    "twelve drummers drumming",
+];
+
+const DAYS: [&str; 12] = [
+    "first",
+    "second",
+    "third",
+    "fourth",
+    "fifth",
+    "sixth",
+    "seventh",
+    "eighth",
+    "nineth",
+    "tenth",
+    "eleventh",
+    "twelth",
+];
+
+// Builds the verse for the given day (zero-indexed).
+fn verse(day: usize) -> String {
+    let mut verse = format!("On the {} day of Christmas, my true love gave to me:\n", DAYS[day]);
+
+    // If day one just give the partridge line.
+    if day == 0 {
+        verse.push_str(&format!("\t{PARTRIDGE}\n"));
+        return verse;
+    }
+
+    // All but the partridge line, in reverse.
+    let day_lines = &LINES[0..day];
+    for line in day_lines.iter().rev() {
+        verse.push_str(&format!("\t{line},\n"));
+    }
+
+    // And the partridge line.
+    verse.push_str(&format!("\tand {PARTRIDGE}.\n"));
+
+    verse
+}
+
+// Builds the full carol, one verse per day.
+fn song() -> String {
+    let mut song = String::new();
+
+    for day in 0..DAYS.len() {
+        song.push_str(&verse(day));
+    }
+
+    song
+}
+
 fn main() {
-    let partidge = "a partridge in a pair tree";
-    let lines = [
-        "two turtle doves",
-        "three French hens",
-        "four calling birds",
-        "fiiiiiivvvve gooolllddd riinnnggs", // necessary!
-        "six geese a-laying",
-        "seven swans a-swimming",
-        "eight maids a-milking",
-        "nine ladies dancing",
-        "ten lords a-leaping",
-        "eleven pipers piping",
-        "twelve drummers drumming",
-    ];
-    let days = [
-        "first",
-        "second",
-        "third",
-        "fourth",
-        "fifth",
-        "sixth",
-        "seventh",
-        "eighth",
-        "nineth",
-        "tenth",
-        "eleventh",
-        "twelth",
-    ];
-
-    // Want both day name and its index value for slicing the lines.
-    for (i, day) in days.iter().enumerate() {
-        println!("On the {day} day of Christmas, my true love gave to me:");
-
-        // If day one just print partridge
-        if i == 0 {
-            println!("\t{partidge}");
-            continue;
+    print!("{}", song());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verse_zero_is_just_the_partridge_line() {
+        let verse = verse(0);
+        assert!(verse.contains(PARTRIDGE));
+        for line in LINES {
+            assert!(!verse.contains(line));
         }
+    }
 
-        // Print all but partidge line
-        // I ideally want to work the array
-        let day_lines = &lines[0..i]; // Slice of the vector
-        for line in day_lines.iter().rev() {
-            println!("\t{line},");
-        };
+    #[test]
+    fn verse_eleven_lists_all_gifts_in_reverse_ending_with_partridge() {
+        let verse = verse(11);
+
+        // Each gift should appear, in reverse order, before the closing partridge line.
+        let mut last_pos = 0;
+        for line in LINES.iter().rev() {
+            let pos = verse.find(line).expect("gift line missing from verse");
+            assert!(pos >= last_pos, "gift lines are not in reverse order");
+            last_pos = pos;
+        }
 
-        // Print "And" partridge line.
-        println!("\tand {partidge}.");
-    };
+        assert!(verse.trim_end().ends_with(&format!("and {PARTRIDGE}.")));
+    }
 }
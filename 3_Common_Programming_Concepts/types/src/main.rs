@@ -17,8 +17,8 @@ fn main() {
 
     // Characters
     let c = 'z';
-    let z: char = 'â„¤'; // with explicit type annotation
-    let heart_eyed_cat = 'ðŸ˜»';
+    let z: char = 'ℤ'; // with explicit type annotation
+    let heart_eyed_cat = '😻';
 
     // Tuple
     let tup: (i32, f64, char) = (500, 4.12, 'u');
@@ -30,4 +30,50 @@ fn main() {
     let u = tup.2;
 
     println!("The value of y is {y}");
+
+    // Integer overflow panics in debug builds but wraps in release builds.
+    // These functions make each overflow strategy explicit instead of relying on that default.
+    println!("checked: {:?}", checked(200, 100));
+    println!("wrapping: {}", wrapping(200, 100));
+    println!("saturating: {}", saturating(200, 100));
+}
+
+// Returns `None` on overflow instead of panicking or wrapping.
+fn checked(a: u8, b: u8) -> Option<u8> {
+    a.checked_add(b)
+}
+
+// Wraps around on overflow, e.g. 200u8.wrapping_add(100) == 44.
+fn wrapping(a: u8, b: u8) -> u8 {
+    a.wrapping_add(b)
+}
+
+// Clamps to the type's max (or min) on overflow, e.g. 200u8.saturating_add(100) == 255.
+fn saturating(a: u8, b: u8) -> u8 {
+    a.saturating_add(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_add_overflow_returns_none() {
+        assert_eq!(checked(200, 100), None);
+    }
+
+    #[test]
+    fn checked_add_no_overflow_returns_sum() {
+        assert_eq!(checked(100, 50), Some(150));
+    }
+
+    #[test]
+    fn wrapping_add_overflow_wraps() {
+        assert_eq!(wrapping(200, 100), 44);
+    }
+
+    #[test]
+    fn saturating_add_overflow_clamps_to_max() {
+        assert_eq!(saturating(200, 100), 255);
+    }
 }
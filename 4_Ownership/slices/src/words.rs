@@ -0,0 +1,80 @@
+// Byte-scanning helpers for splitting a string into space-delimited words.
+
+/// Returns the first word in `s`, skipping leading spaces, or `""` if there is none.
+pub fn first_word(s: &str) -> &str {
+    words(s).next().unwrap_or("")
+}
+
+/// Returns the `n`th word (zero-indexed) in `s`, skipping runs of consecutive spaces.
+pub fn nth_word(s: &str, n: usize) -> Option<&str> {
+    words(s).nth(n)
+}
+
+/// Returns an iterator over all space-delimited words in `s`; only `' '` separates words.
+pub fn words(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+
+    std::iter::from_fn(move || {
+        // Skip any run of leading spaces.
+        while i < bytes.len() && bytes[i] == b' ' {
+            i += 1;
+        }
+
+        if i >= bytes.len() {
+            return None;
+        }
+
+        let start = i;
+        while i < bytes.len() && bytes[i] != b' ' {
+            i += 1;
+        }
+
+        Some(&s[start..i])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_word_single_word() {
+        assert_eq!(first_word("hello"), "hello");
+    }
+
+    #[test]
+    fn first_word_leading_spaces() {
+        assert_eq!(first_word("  hello there"), "hello");
+    }
+
+    #[test]
+    fn words_skips_consecutive_spaces() {
+        let result: Vec<&str> = words("a   b").collect();
+        assert_eq!(result, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn words_leading_and_trailing_spaces() {
+        let result: Vec<&str> = words("  a b  ").collect();
+        assert_eq!(result, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn words_empty_input() {
+        let result: Vec<&str> = words("").collect();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn words_single_word() {
+        let result: Vec<&str> = words("hello").collect();
+        assert_eq!(result, vec!["hello"]);
+    }
+
+    #[test]
+    fn nth_word_returns_requested_word() {
+        assert_eq!(nth_word("a   b c", 1), Some("b"));
+        assert_eq!(nth_word("a   b c", 3), None);
+    }
+}
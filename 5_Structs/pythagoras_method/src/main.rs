@@ -1,3 +1,4 @@
+#[derive(Debug, Clone, Copy)]
 struct Point2 {
     x: f32,
     y: f32,
@@ -6,13 +7,77 @@ struct Point2 {
 impl Point2 {
     fn angle(&self) -> f32 {
         return (self.y/self.x).atan();
-    }    
+    }
 }
 
 impl Point2 {
     fn length(&self) -> f32 {
         return (self.x.powi(2) + self.y.powi(2)).sqrt()
-    }    
+    }
+}
+
+impl Point2 {
+    fn dot(&self, other: &Point2) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    fn normalize(&self) -> Point2 {
+        let length = self.length();
+
+        // Avoid dividing by zero for the zero vector.
+        if length == 0.0 {
+            return Point2 { x: 0.0, y: 0.0 };
+        }
+
+        Point2 {
+            x: self.x / length,
+            y: self.y / length,
+        }
+    }
+
+    fn angle_between(&self, other: &Point2) -> f32 {
+        let denom = self.length() * other.length();
+
+        // Avoid dividing by zero (and returning NaN) when either vector is the zero vector.
+        if denom == 0.0 {
+            return 0.0;
+        }
+
+        (self.dot(other) / denom).acos()
+    }
+}
+
+impl std::ops::Add for Point2 {
+    type Output = Point2;
+
+    fn add(self, other: Point2) -> Point2 {
+        Point2 {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl std::ops::Sub for Point2 {
+    type Output = Point2;
+
+    fn sub(self, other: Point2) -> Point2 {
+        Point2 {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl std::ops::Mul<f32> for Point2 {
+    type Output = Point2;
+
+    fn mul(self, scalar: f32) -> Point2 {
+        Point2 {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
 }
 
 fn main() {
@@ -23,4 +88,16 @@ fn main() {
 
     println!("The point is {} units from the origin.", point1.length());
     println!("The angle of the point to the origin is {}", point1.angle());
+
+    let point2 = Point2 {
+        x: 5.0,
+        y: -3.0,
+    };
+
+    println!("The angle between the points is {}", point1.angle_between(&point2));
+
+    println!("Sum of the points is {:?}", point1 + point2);
+    println!("Difference of the points is {:?}", point1 - point2);
+    println!("Point 1 scaled by 2 is {:?}", point1 * 2.0);
+    println!("The normalized point2 is {:?}", point2.normalize());
 }
@@ -1,10 +1,4 @@
-#[derive(Debug)]
-enum Elf {
-    Dark(String),
-    High(String),
-    Orc(String),
-    Wood(String),
-}
+use std::ops::Add;
 
 #[derive(Debug)]
 struct MagicBonus {
@@ -14,54 +8,113 @@ struct MagicBonus {
     illusion: u8,
 }
 
-fn set_magic_bonus(race: &Elf) -> MagicBonus {
-    match race {
-        Elf::Dark(character_name) => {
-            println!("Selected: {:?}", race);
-            println!("Character name: {}", character_name);
-            MagicBonus {
-                alteration: 5,
-                conjuration: 0,
-                destruction: 10,
-                illusion: 5,
-            }
-        },
-        Elf::High(character_name) => {
-            println!("Selected: {:?}", race);
-            println!("Character name: {}", character_name);
-            MagicBonus {
-                alteration: 5,
-                conjuration: 5,
-                destruction: 5,
-                illusion: 5,
-            }
-        },
-        Elf::Orc(character_name) => {
-            println!("Selected: {:?}", race);
-            println!("Character name: {}", character_name);
-            MagicBonus {
-                alteration: 0,
-                conjuration: 0,
-                destruction: 0,
-                illusion: 0,
-            }
-        },
-        Elf::Wood(character_name) => {
-            println!("Selected: {:?}", race);
-            println!("Character name: {}", character_name);
-            MagicBonus {
-                alteration: 5,
-                conjuration: 0,
-                destruction: 0,
-                illusion: 5,
-            }
-        },
+impl Add for MagicBonus {
+    type Output = MagicBonus;
+
+    // Saturating add so stacked bonuses can't overflow `u8` and panic.
+    fn add(self, other: MagicBonus) -> MagicBonus {
+        MagicBonus {
+            alteration: self.alteration.saturating_add(other.alteration),
+            conjuration: self.conjuration.saturating_add(other.conjuration),
+            destruction: self.destruction.saturating_add(other.destruction),
+            illusion: self.illusion.saturating_add(other.illusion),
+        }
+    }
+}
+
+trait Race {
+    fn name(&self) -> &str;
+    fn base_bonus(&self) -> MagicBonus;
+}
+
+struct Dark;
+struct High;
+struct Orc;
+struct Wood;
+
+impl Race for Dark {
+    fn name(&self) -> &str {
+        "Dark Elf"
+    }
+
+    fn base_bonus(&self) -> MagicBonus {
+        MagicBonus {
+            alteration: 5,
+            conjuration: 0,
+            destruction: 10,
+            illusion: 5,
+        }
+    }
+}
+
+impl Race for High {
+    fn name(&self) -> &str {
+        "High Elf"
+    }
+
+    fn base_bonus(&self) -> MagicBonus {
+        MagicBonus {
+            alteration: 5,
+            conjuration: 5,
+            destruction: 5,
+            illusion: 5,
+        }
+    }
+}
+
+impl Race for Orc {
+    fn name(&self) -> &str {
+        "Orc"
+    }
+
+    fn base_bonus(&self) -> MagicBonus {
+        MagicBonus {
+            alteration: 0,
+            conjuration: 0,
+            destruction: 0,
+            illusion: 0,
+        }
+    }
+}
+
+impl Race for Wood {
+    fn name(&self) -> &str {
+        "Wood Elf"
+    }
+
+    fn base_bonus(&self) -> MagicBonus {
+        MagicBonus {
+            alteration: 5,
+            conjuration: 0,
+            destruction: 0,
+            illusion: 5,
+        }
+    }
+}
+
+struct Character {
+    name: String,
+    race: Box<dyn Race>,
+}
+
+impl Character {
+    fn new(name: &str, race: Box<dyn Race>) -> Character {
+        Character {
+            name: String::from(name),
+            race,
+        }
+    }
+
+    // Future class/equipment bonuses can be summed in here alongside the race bonus.
+    fn total_bonus(&self) -> MagicBonus {
+        self.race.base_bonus()
     }
 }
 
 fn main() {
-    let character = Elf::Orc(String::from("Oswald"));
+    let character = Character::new("Oswald", Box::new(Orc));
 
-    let bonus = set_magic_bonus(&character);
-    println!("{:?}", bonus);
+    println!("Selected: {}", character.race.name());
+    println!("Character name: {}", character.name);
+    println!("{:?}", character.total_bonus());
 }